@@ -4,6 +4,7 @@ use crate::vk;
 use crate::RawPtr;
 use shared_library::dynamic_library::DynamicLibrary;
 use std::error::Error;
+use std::ffi::CStr;
 use std::fmt;
 use std::mem;
 use std::os::raw::c_char;
@@ -104,24 +105,38 @@ pub trait EntryV1_0 {
 
     #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.1-extensions/man/html/vkEnumerateInstanceExtensionProperties.html>"]
     fn enumerate_instance_extension_properties(&self) -> VkResult<Vec<vk::ExtensionProperties>> {
-        unsafe {
-            let mut num = 0;
-            self.fp_v1_0().enumerate_instance_extension_properties(
-                ptr::null(),
-                &mut num,
-                ptr::null_mut(),
-            );
-            let mut data = Vec::with_capacity(num as usize);
-            let err_code = self.fp_v1_0().enumerate_instance_extension_properties(
-                ptr::null(),
-                &mut num,
-                data.as_mut_ptr(),
-            );
-            data.set_len(num as usize);
-            match err_code {
-                vk::Result::SUCCESS => Ok(data),
-                _ => Err(err_code),
-            }
+        unsafe { self.enumerate_instance_extension_properties_for_layer_raw(ptr::null()) }
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.1-extensions/man/html/vkEnumerateInstanceExtensionProperties.html>"]
+    fn enumerate_instance_extension_properties_for_layer(
+        &self,
+        layer_name: &CStr,
+    ) -> VkResult<Vec<vk::ExtensionProperties>> {
+        unsafe { self.enumerate_instance_extension_properties_for_layer_raw(layer_name.as_ptr()) }
+    }
+
+    #[doc(hidden)]
+    unsafe fn enumerate_instance_extension_properties_for_layer_raw(
+        &self,
+        p_layer_name: *const c_char,
+    ) -> VkResult<Vec<vk::ExtensionProperties>> {
+        let mut num = 0;
+        self.fp_v1_0().enumerate_instance_extension_properties(
+            p_layer_name,
+            &mut num,
+            ptr::null_mut(),
+        );
+        let mut data = Vec::with_capacity(num as usize);
+        let err_code = self.fp_v1_0().enumerate_instance_extension_properties(
+            p_layer_name,
+            &mut num,
+            data.as_mut_ptr(),
+        );
+        data.set_len(num as usize);
+        match err_code {
+            vk::Result::SUCCESS => Ok(data),
+            _ => Err(err_code),
         }
     }
 
@@ -211,6 +226,50 @@ impl EntryCustom<Arc<DynamicLibrary>> {
             },
         )
     }
+
+    /// Loads the Vulkan library from `path` instead of the platform's `LIB_PATH`.
+    pub fn new_from_path(path: &Path) -> Result<Entry, LoadingError> {
+        Self::new_custom(
+            || {
+                DynamicLibrary::open(Some(path))
+                    .map_err(|err| {
+                        LoadingError::LibraryLoadError(format!(
+                            "failed to open Vulkan library at `{}`: {}",
+                            path.display(),
+                            err
+                        ))
+                    })
+                    .map(Arc::new)
+            },
+            |vk_lib, name| unsafe {
+                vk_lib
+                    .symbol(&*name.to_string_lossy())
+                    .unwrap_or(ptr::null_mut())
+            },
+        )
+    }
+
+    /// Convenience alias for [`Self::new_from_path`].
+    pub fn load_from(path: &Path) -> Result<Entry, LoadingError> {
+        Self::new_from_path(path)
+    }
+}
+
+/// Placeholder `L` library slot for an [`EntryCustom`] that owns no [`DynamicLibrary`].
+#[derive(Clone, Debug, Default)]
+pub struct NoLibrary;
+
+impl EntryCustom<NoLibrary> {
+    /// Builds an `EntryCustom` from a `vkGetInstanceProcAddr` function pointer the caller
+    /// already has in hand, without ash opening or owning a [`DynamicLibrary`] itself.
+    pub fn from_get_instance_proc_addr(
+        pfn: vk::PFN_vkGetInstanceProcAddr,
+    ) -> Result<Self, LoadingError> {
+        Self::new_custom(
+            || Ok(NoLibrary),
+            |_, name| unsafe { mem::transmute(pfn(vk::Instance::null(), name.as_ptr())) },
+        )
+    }
 }
 
 impl<L> EntryCustom<L> {
@@ -0,0 +1,216 @@
+use crate::prelude::*;
+use crate::vk;
+use crate::RawPtr;
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::c_void;
+use std::panic;
+use std::sync::Mutex;
+
+/// Wraps `VK_EXT_debug_utils`, letting callers register a validation/diagnostics
+/// callback without writing their own `extern "system"` trampoline.
+#[derive(Clone)]
+pub struct DebugUtils {
+    handle: vk::Instance,
+    debug_utils_fn: vk::ExtDebugUtilsFn,
+}
+
+impl DebugUtils {
+    pub fn new(entry: &crate::Entry, instance: &crate::Instance) -> Self {
+        let debug_utils_fn = vk::ExtDebugUtilsFn::load(|name| unsafe {
+            mem::transmute(entry.get_instance_proc_addr(instance.handle(), name.as_ptr()))
+        });
+        Self {
+            handle: instance.handle(),
+            debug_utils_fn,
+        }
+    }
+
+    pub fn name() -> &'static CStr {
+        vk::ExtDebugUtilsFn::name()
+    }
+
+    pub fn fp(&self) -> &vk::ExtDebugUtilsFn {
+        &self.debug_utils_fn
+    }
+
+    pub fn instance(&self) -> vk::Instance {
+        self.handle
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.1-extensions/man/html/vkCreateDebugUtilsMessengerEXT.html>"]
+    pub unsafe fn create_debug_utils_messenger(
+        &self,
+        create_info: &vk::DebugUtilsMessengerCreateInfoEXT,
+        allocator: Option<&vk::AllocationCallbacks>,
+    ) -> VkResult<vk::DebugUtilsMessengerEXT> {
+        let mut messenger = mem::zeroed();
+        let err_code = self.debug_utils_fn.create_debug_utils_messenger_ext(
+            self.handle,
+            create_info,
+            allocator.as_raw_ptr(),
+            &mut messenger,
+        );
+        match err_code {
+            vk::Result::SUCCESS => Ok(messenger),
+            _ => Err(err_code),
+        }
+    }
+
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.1-extensions/man/html/vkDestroyDebugUtilsMessengerEXT.html>"]
+    pub unsafe fn destroy_debug_utils_messenger(
+        &self,
+        messenger: vk::DebugUtilsMessengerEXT,
+        allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+        self.debug_utils_fn.destroy_debug_utils_messenger_ext(
+            self.handle,
+            messenger,
+            allocator.as_raw_ptr(),
+        );
+    }
+
+    /// Creates a messenger that invokes `callback`, boxing it up behind `p_user_data` and
+    /// setting a single static trampoline as `pfn_user_callback` instead of requiring
+    /// every caller to write their own `extern "system"` shim.
+    ///
+    /// The validation layer may call the messenger callback concurrently from whichever
+    /// threads the application is making Vulkan calls from, so the boxed state is kept
+    /// behind a `Mutex` and the trampoline locks it before touching `callback`.
+    ///
+    /// `ignored_message_ids` lets callers silence known false-positive validation-layer
+    /// messages (matched against `message_id_number`) without filtering inside their own
+    /// closure.
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.1-extensions/man/html/vkCreateDebugUtilsMessengerEXT.html>"]
+    pub unsafe fn create_debug_utils_messenger_callback<F>(
+        &self,
+        message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+        message_type: vk::DebugUtilsMessageTypeFlagsEXT,
+        ignored_message_ids: Vec<i32>,
+        callback: F,
+    ) -> VkResult<DebugUtilsMessengerCallback>
+    where
+        F: FnMut(
+                vk::DebugUtilsMessageSeverityFlagsEXT,
+                vk::DebugUtilsMessageTypeFlagsEXT,
+                &vk::DebugUtilsMessengerCallbackDataEXT,
+            ) + Send
+            + 'static,
+    {
+        let state = Box::new(Mutex::new(CallbackState {
+            callback: Box::new(callback),
+            ignored_message_ids,
+        }));
+
+        let create_info = vk::DebugUtilsMessengerCreateInfoEXT {
+            message_severity,
+            message_type,
+            pfn_user_callback: Some(debug_utils_messenger_trampoline),
+            p_user_data: state.as_ref() as *const Mutex<CallbackState> as *mut c_void,
+            ..Default::default()
+        };
+
+        let messenger = self.create_debug_utils_messenger(&create_info, None)?;
+
+        Ok(DebugUtilsMessengerCallback { messenger, state })
+    }
+
+    /// Destroys a messenger previously created with [`Self::create_debug_utils_messenger_callback`]
+    /// and frees the boxed closure behind it. The closure is only dropped after the
+    /// validation layer can no longer invoke it.
+    pub unsafe fn destroy_debug_utils_messenger_callback(
+        &self,
+        callback: DebugUtilsMessengerCallback,
+    ) {
+        self.destroy_debug_utils_messenger(callback.messenger, None);
+    }
+}
+
+/// A messenger created through [`DebugUtils::create_debug_utils_messenger_callback`].
+/// Owns the boxed closure it was created with; pass it to
+/// [`DebugUtils::destroy_debug_utils_messenger_callback`] to tear both down together.
+pub struct DebugUtilsMessengerCallback {
+    messenger: vk::DebugUtilsMessengerEXT,
+    // Never read directly: the trampoline reaches `CallbackState` through the raw
+    // `p_user_data` pointer. Held here only so the boxed `Mutex` stays alive until
+    // `destroy_debug_utils_messenger_callback` drops it.
+    #[allow(dead_code)]
+    state: Box<Mutex<CallbackState>>,
+}
+
+impl DebugUtilsMessengerCallback {
+    pub fn messenger(&self) -> vk::DebugUtilsMessengerEXT {
+        self.messenger
+    }
+}
+
+struct CallbackState {
+    callback: Box<
+        dyn FnMut(
+                vk::DebugUtilsMessageSeverityFlagsEXT,
+                vk::DebugUtilsMessageTypeFlagsEXT,
+                &vk::DebugUtilsMessengerCallbackDataEXT,
+            ) + Send,
+    >,
+    ignored_message_ids: Vec<i32>,
+}
+
+unsafe extern "system" fn debug_utils_messenger_trampoline(
+    message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    message_types: vk::DebugUtilsMessageTypeFlagsEXT,
+    p_callback_data: *const vk::DebugUtilsMessengerCallbackDataEXT,
+    p_user_data: *mut c_void,
+) -> vk::Bool32 {
+    // The validation layer may invoke this callback concurrently from whichever
+    // threads the application is making Vulkan calls from, so `CallbackState` is
+    // only ever touched through this lock.
+    let state = &*(p_user_data as *const Mutex<CallbackState>);
+    let mut state = match state.lock() {
+        Ok(state) => state,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    let callback_data = &*p_callback_data;
+
+    if state
+        .ignored_message_ids
+        .contains(&callback_data.message_id_number)
+    {
+        return vk::FALSE;
+    }
+
+    // A panic unwinding across this FFI boundary is undefined behavior; catch it
+    // here instead of letting it propagate into the validation layer's call stack.
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        (state.callback)(message_severity, message_types, callback_data);
+    }));
+
+    vk::FALSE
+}
+
+/// A built-in callback for [`DebugUtils::create_debug_utils_messenger_callback`] that forwards
+/// validation-layer messages to the `log` crate at a level derived from `message_severity`.
+pub fn default_log_callback(
+    message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    message_type: vk::DebugUtilsMessageTypeFlagsEXT,
+    callback_data: &vk::DebugUtilsMessengerCallbackDataEXT,
+) {
+    let level = if message_severity.contains(vk::DebugUtilsMessageSeverityFlagsEXT::ERROR) {
+        log::Level::Error
+    } else if message_severity.contains(vk::DebugUtilsMessageSeverityFlagsEXT::WARNING) {
+        log::Level::Warn
+    } else if message_severity.contains(vk::DebugUtilsMessageSeverityFlagsEXT::INFO) {
+        log::Level::Info
+    } else {
+        log::Level::Debug
+    };
+
+    let message = unsafe {
+        if callback_data.p_message.is_null() {
+            std::borrow::Cow::Borrowed("")
+        } else {
+            CStr::from_ptr(callback_data.p_message).to_string_lossy()
+        }
+    };
+
+    log::log!(level, "{:?}: {}", message_type, message);
+}
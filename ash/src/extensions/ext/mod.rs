@@ -0,0 +1,4 @@
+mod debug_utils;
+
+pub use self::debug_utils::DebugUtils;
+pub use self::debug_utils::DebugUtilsMessengerCallback;